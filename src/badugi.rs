@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+use crate::{distinct_ranks, distinct_suits, rank_value, Card, Rank};
+
+// The cards that play in a badugi hand: the largest group with no suit and no
+// rank repeated. Compares so that the better hand is greater, meaning more
+// cards first and then the lower cards, Ace low, from the top down.
+#[derive(Debug, Clone)]
+pub struct BadugiHand {
+    pub cards: Vec<Card>,
+}
+
+impl BadugiHand {
+    // Playing card values with the Ace as 1, highest first
+    fn low_values(&self) -> Vec<u8> {
+        let mut values: Vec<u8> = self
+            .cards
+            .iter()
+            .map(|card| match card.rank {
+                Rank::Ace => 1,
+                _ => rank_value(&card.rank),
+            })
+            .collect();
+        values.sort_by(|a, b| b.cmp(a));
+        values
+    }
+}
+
+impl Ord for BadugiHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cards
+            .len()
+            .cmp(&other.cards.len())
+            .then_with(|| other.low_values().cmp(&self.low_values()))
+    }
+}
+
+impl PartialOrd for BadugiHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BadugiHand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BadugiHand {}
+
+pub fn evaluate_badugi(hand: &[Card; 4]) -> BadugiHand {
+    (1..16u8)
+        .map(|mask| BadugiHand {
+            cards: hand
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, card)| card.clone())
+                .collect(),
+        })
+        .filter(|candidate| {
+            let size = candidate.cards.len();
+            distinct_suits(&candidate.cards) == size && distinct_ranks(&candidate.cards) == size
+        })
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> [Card; 4] {
+        let cards: Vec<Card> = cards.split_whitespace().map(|card| card.parse().unwrap()).collect();
+        cards.try_into().unwrap()
+    }
+
+    #[test]
+    fn four_suits_and_ranks_make_a_badugi() {
+        let badugi = evaluate_badugi(&hand("AS 2H 3D 4C"));
+        assert_eq!(badugi.cards.len(), 4);
+    }
+
+    #[test]
+    fn two_cards_of_a_suit_leave_three() {
+        let three_card = evaluate_badugi(&hand("AS 2H 3D 4D"));
+
+        assert_eq!(three_card.cards.len(), 3);
+        assert!(three_card.cards.contains(&"3D".parse().unwrap()));
+        assert!(three_card < evaluate_badugi(&hand("KS QH JD 10C")));
+    }
+}
@@ -0,0 +1,2145 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+pub mod badugi;
+pub mod lowball;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod three_card;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+impl Suit {
+    // English name, the base for any localized wording
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Spades => "Spades",
+            Suit::Hearts => "Hearts",
+            Suit::Diamonds => "Diamonds",
+            Suit::Clubs => "Clubs",
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Suit::Spades => '♠',
+            Suit::Hearts => '♥',
+            Suit::Diamonds => '♦',
+            Suit::Clubs => '♣',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Rank {
+    Ace,
+    Number(u8), // 2 through 10 in a standard deck, 1 through 10 in numeric decks; never above 10
+    Jack,
+    Queen,
+    King,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Card {
+    pub suit: Suit,
+    pub rank: Rank,
+}
+
+// Why a card or a saved deck could not be read
+#[derive(Debug)]
+pub enum CardParseError {
+    InvalidCard(String),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for CardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardParseError::InvalidCard(token) => write!(f, "invalid card: {:?}", token),
+            CardParseError::Io(err) => write!(f, "could not read deck: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CardParseError {}
+
+impl From<std::io::Error> for CardParseError {
+    fn from(err: std::io::Error) -> Self {
+        CardParseError::Io(err)
+    }
+}
+
+// Parses what display_card writes, like "A♠" or "10♥". Suits may also be
+// given as the letters S, H, D or C.
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CardParseError::InvalidCard(s.to_string());
+
+        let mut chars = s.chars();
+        let suit = match chars.next_back().ok_or_else(invalid)? {
+            '♠' | 'S' | 's' => Suit::Spades,
+            '♥' | 'H' | 'h' => Suit::Hearts,
+            '♦' | 'D' | 'd' => Suit::Diamonds,
+            '♣' | 'C' | 'c' => Suit::Clubs,
+            _ => return Err(invalid()),
+        };
+
+        let rank = match chars.as_str() {
+            "A" | "a" => Rank::Ace,
+            "J" | "j" => Rank::Jack,
+            "Q" | "q" => Rank::Queen,
+            "K" | "k" => Rank::King,
+            "10" => Rank::Number(10),
+            // Only the bare digits 1 to 9, so "05" or "+5" are not read as a 5
+            number => match number.as_bytes() {
+                [digit @ b'1'..=b'9'] => Rank::Number(digit - b'0'),
+                _ => return Err(invalid()),
+            },
+        };
+
+        Ok(Card { suit, rank })
+    }
+}
+
+// A card as it sits in front of a player, face up or face down
+#[derive(Debug, Clone)]
+pub struct DealtCard {
+    pub card: Card,
+    pub face_up: bool,
+}
+
+impl Card {
+    // Usable in consts and statics, e.g.
+    // const ACE_OF_SPADES: Card = Card::new(Rank::Ace, Suit::Spades);
+    // A Number rank outside 1 through 10 panics, at compile time in a const.
+    pub const fn new(rank: Rank, suit: Suit) -> Card {
+        if let Rank::Number(n) = rank {
+            assert!(n >= 1 && n <= 10, "Number ranks run from 1 to 10");
+        }
+        Card { suit, rank }
+    }
+}
+
+impl From<(Rank, Suit)> for Card {
+    fn from((rank, suit): (Rank, Suit)) -> Self {
+        Card { suit, rank }
+    }
+}
+
+impl From<Card> for (Rank, Suit) {
+    fn from(card: Card) -> Self {
+        (card.rank, card.suit)
+    }
+}
+
+// Several decks shuffled together. Cards come off in order past a dealt marker,
+// so nothing repeats until the shoe is reshuffled.
+#[derive(Debug, Clone)]
+pub struct Shoe {
+    cards: Vec<Card>,
+    dealt: usize,
+}
+
+impl Shoe {
+    pub fn new(num_decks: usize) -> Self {
+        Shoe::new_with(num_decks, &mut rand::rng())
+    }
+
+    pub fn new_with<R: Rng + ?Sized>(num_decks: usize, rng: &mut R) -> Self {
+        let mut shoe = Shoe {
+            cards: (0..num_decks).flat_map(|_| generate_deck()).collect(),
+            dealt: 0,
+        };
+        shoe.reshuffle_with(rng);
+        shoe
+    }
+
+    // Next undealt card, or None once the whole shoe is out
+    pub fn draw(&mut self) -> Option<Card> {
+        let card = self.cards.get(self.dealt)?.clone();
+        self.dealt += 1;
+        Some(card)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.cards.len() - self.dealt
+    }
+
+    // Gather every card back in and shuffle
+    pub fn reshuffle(&mut self) {
+        self.reshuffle_with(&mut rand::rng());
+    }
+
+    pub fn reshuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+        self.dealt = 0;
+    }
+}
+
+// Function to generate a full 52-card deck
+pub fn generate_deck() -> Vec<Card> {
+    let suits = vec![
+        Suit::Spades,
+        Suit::Hearts,
+        Suit::Diamonds,
+        Suit::Clubs,
+    ];
+
+    let mut deck = Vec::new();
+
+    for suit in suits {
+        deck.extend(suit_sequence(suit));
+    }
+
+    deck
+}
+
+// Cards of a standard deck that are not among the given ones. Duplicates in
+// the input are harmless, and cards outside a standard deck are ignored.
+pub fn missing_cards(present: &[Card]) -> Vec<Card> {
+    let present: HashSet<&Card> = present.iter().collect();
+
+    generate_deck()
+        .into_iter()
+        .filter(|card| !present.contains(card))
+        .collect()
+}
+
+// Every way to draw cards_needed more cards from the standard cards not in known,
+// one combination at a time in deck order, without building them all up front
+pub fn runouts(known: &[Card], cards_needed: usize) -> impl Iterator<Item = Vec<Card>> {
+    let pool = missing_cards(known);
+    let n = pool.len();
+    let k = cards_needed;
+
+    let mut indices: Option<Vec<usize>> = if k <= n { Some((0..k).collect()) } else { None };
+
+    std::iter::from_fn(move || {
+        let current = indices.take()?;
+        let runout = current.iter().map(|&i| pool[i].clone()).collect();
+
+        // Step to the next combination: bump the rightmost index that can still move
+        if let Some(i) = (0..k).rev().find(|&i| current[i] != i + n - k) {
+            let mut next = current;
+            next[i] += 1;
+            for j in i + 1..k {
+                next[j] = next[j - 1] + 1;
+            }
+            indices = Some(next);
+        }
+
+        Some(runout)
+    })
+}
+
+// Check that no card appears twice, as a single deck requires. On failure
+// returns each repeated card once, in the order the repeats were found.
+pub fn validate_unique(cards: &[Card]) -> Result<(), Vec<Card>> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+
+    for card in cards {
+        if !seen.insert(card) && !duplicates.contains(card) {
+            duplicates.push(card.clone());
+        }
+    }
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(duplicates)
+    }
+}
+
+// True if the cards are exactly a fresh generate_deck(), in the same order
+pub fn is_sorted_standard(cards: &[Card]) -> bool {
+    cards == generate_deck().as_slice()
+}
+
+// Function to generate the 13 cards of one suit, Ace up through King
+pub fn suit_sequence(suit: Suit) -> Vec<Card> {
+    let mut cards = Vec::new();
+
+    // Add Ace
+    cards.push(Card { suit: suit.clone(), rank: Rank::Ace });
+
+    // Add 2 through 10
+    for n in 2..=10 {
+        cards.push(Card { suit: suit.clone(), rank: Rank::Number(n) });
+    }
+
+    // Add Jack, Queen, King
+    cards.push(Card { suit: suit.clone(), rank: Rank::Jack });
+    cards.push(Card { suit: suit.clone(), rank: Rank::Queen });
+    cards.push(Card { suit, rank: Rank::King });
+
+    cards
+}
+
+// Function to generate the standard cards the predicate accepts, in the usual
+// deck order, from stripped decks to single-suit or red-only ones
+pub fn build_deck<F: Fn(&Rank, &Suit) -> bool>(include: F) -> Vec<Card> {
+    generate_deck()
+        .into_iter()
+        .filter(|card| include(&card.rank, &card.suit))
+        .collect()
+}
+
+// Function to generate a numbers-only deck, 1 through max in each given suit.
+// There are no aces or face cards, so Number(1) stands in as the lowest card.
+// A max above 10 panics, since Number(11) and up would pass for face cards.
+pub fn generate_numeric_deck(max: u8, suits: &[Suit]) -> Vec<Card> {
+    if max > 10 {
+        panic!("Numeric decks go up to 10 but {} was requested.", max);
+    }
+
+    let mut deck = Vec::new();
+
+    for suit in suits {
+        for n in 1..=max {
+            deck.push(Card { suit: suit.clone(), rank: Rank::Number(n) });
+        }
+    }
+
+    deck
+}
+
+// Helper function to display a card nicely
+pub fn display_card(card: &Card) -> String {
+    format!("{}{}", rank_label(&card.rank), card.suit.symbol())
+}
+
+fn rank_label(rank: &Rank) -> String {
+    match rank {
+        Rank::Ace => "A".to_string(),
+        Rank::Number(n) => n.to_string(),
+        Rank::Jack => "J".to_string(),
+        Rank::Queen => "Q".to_string(),
+        Rank::King => "K".to_string(),
+    }
+}
+
+// The single Unicode playing-card character for a card, e.g. 🂡 for the Ace of
+// Spades. Each suit's block runs Ace, 2-10, Jack, Knight, Queen, King, so the
+// Queen and King sit one past the Knight that standard decks leave out.
+// Number ranks outside 2 through 10, like a numeric deck's Number(1), have no
+// glyph of their own and give None.
+pub fn display_card_glyph(card: &Card) -> Option<char> {
+    let base = match card.suit {
+        Suit::Spades => 0x1F0A0,
+        Suit::Hearts => 0x1F0B0,
+        Suit::Diamonds => 0x1F0C0,
+        Suit::Clubs => 0x1F0D0,
+    };
+
+    let offset = match card.rank {
+        Rank::Ace => 1,
+        Rank::Number(n) if (2..=10).contains(&n) => n as u32,
+        Rank::Number(_) => return None,
+        Rank::Jack => 11,
+        Rank::Queen => 13,
+        Rank::King => 14,
+    };
+
+    char::from_u32(base + offset)
+}
+
+// Display a hand with the cards at hidden_indices shown face down, like a
+// blackjack dealer's hole card
+pub fn display_hand_hidden(cards: &[Card], hidden_indices: &[usize]) -> String {
+    cards
+        .iter()
+        .enumerate()
+        .map(|(i, card)| {
+            if hidden_indices.contains(&i) {
+                "[##]".to_string()
+            } else {
+                display_card(card)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Draw a card as a small box, one string per line. The rank column is
+// three wide so "10" lines up with the single-character ranks.
+pub fn render_card_art(card: &Card) -> Vec<String> {
+    let rank = rank_label(&card.rank);
+
+    vec![
+        "┌───┐".to_string(),
+        format!("│{:<3}│", rank),
+        format!("│ {} │", card.suit.symbol()),
+        format!("│{:>3}│", rank),
+        "└───┘".to_string(),
+    ]
+}
+
+// Show cards overlapped like a held fan: every card but the last is covered
+// except for its left strip, which shows the rank corner and the suit below it.
+// With visible_rank_only the covered strips show just the rank.
+pub fn render_deck_fanned(cards: &[Card], visible_rank_only: bool) -> String {
+    let Some((last, covered)) = cards.split_last() else {
+        return String::new();
+    };
+
+    let mut lines = render_card_art(last);
+    for card in covered.iter().rev() {
+        let suit = if visible_rank_only { ' ' } else { card.suit.symbol() };
+        let strip = [
+            "┌──".to_string(),
+            format!("│{:<2}", rank_label(&card.rank)),
+            format!("│ {}", suit),
+            "│  ".to_string(),
+            "└──".to_string(),
+        ];
+        for (line, strip) in lines.iter_mut().zip(strip) {
+            line.insert_str(0, &strip);
+        }
+    }
+
+    lines.join("\n")
+}
+
+// Grid of which standard cards are present, one row per suit and one column per
+// rank from Ace to King. Present cards show as "#" and missing ones as ".".
+pub fn render_deck_grid(cards: &[Card]) -> String {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    let mut grid = " ".to_string();
+    for card in suit_sequence(Suit::Spades) {
+        grid.push_str(&format!("{:>3}", rank_label(&card.rank)));
+    }
+
+    for suit in suits {
+        grid.push('\n');
+        grid.push(suit.symbol());
+        for card in suit_sequence(suit) {
+            grid.push_str(if cards.contains(&card) { "  #" } else { "  ." });
+        }
+    }
+
+    grid
+}
+
+// Lay several card boxes out side by side, one string per line
+pub fn render_cards_art(cards: &[Card]) -> Vec<String> {
+    let arts: Vec<Vec<String>> = cards.iter().map(render_card_art).collect();
+
+    (0..5)
+        .map(|line| {
+            arts.iter()
+                .map(|art| art[line].as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+// Numeric value of a rank, counting the Ace high
+fn rank_value(rank: &Rank) -> u8 {
+    match rank {
+        Rank::Number(n) => *n,
+        Rank::Jack => 11,
+        Rank::Queen => 12,
+        Rank::King => 13,
+        Rank::Ace => 14,
+    }
+}
+
+fn rank_from_value(value: u8) -> Rank {
+    match value {
+        1 | 14 => Rank::Ace,
+        11 => Rank::Jack,
+        12 => Rank::Queen,
+        13 => Rank::King,
+        n => Rank::Number(n),
+    }
+}
+
+// Count how many different suits appear among the cards
+pub fn distinct_suits(cards: &[Card]) -> usize {
+    cards.iter().map(|card| &card.suit).collect::<HashSet<_>>().len()
+}
+
+// Count how many different ranks appear among the cards
+pub fn distinct_ranks(cards: &[Card]) -> usize {
+    cards.iter().map(|card| &card.rank).collect::<HashSet<_>>().len()
+}
+
+// Each rank in the cards with how many times it appears, largest groups first
+// and higher ranks first within the same count
+pub fn rank_groups(cards: &[Card]) -> Vec<(Rank, usize)> {
+    let mut counts: HashMap<Rank, usize> = HashMap::new();
+    for card in cards {
+        *counts.entry(card.rank.clone()).or_insert(0) += 1;
+    }
+
+    let mut groups: Vec<(Rank, usize)> = counts.into_iter().collect();
+    groups.sort_by(|(a_rank, a_count), (b_rank, b_count)| {
+        b_count
+            .cmp(a_count)
+            .then(rank_value(b_rank).cmp(&rank_value(a_rank)))
+    });
+    groups
+}
+
+// True if the hole cards make a flush with the board and hold the highest card
+// of that suit that is not already on the board, so no other flush can beat it.
+// Straight flushes are not considered.
+pub fn is_nut_flush(hole: &[Card; 2], board: &[Card]) -> bool {
+    let Some(suit) = board_flush_suit(board) else {
+        return false;
+    };
+
+    let in_suit = |cards: &[Card]| cards.iter().filter(|card| card.suit == suit).count();
+    if in_suit(board) + in_suit(hole) < 5 {
+        return false;
+    }
+
+    match top_live_card(&suit, board) {
+        Some(card) => hole.contains(&card),
+        None => false,
+    }
+}
+
+// Strong hands an opponent cannot hold because the hero has a card they need:
+// the top live card of the board's flush suit, and any card that would finish
+// a straight flush with three or more suited board cards
+pub fn blockers(hole: &[Card; 2], board: &[Card]) -> Vec<String> {
+    let mut blocked = Vec::new();
+
+    let Some(suit) = board_flush_suit(board) else {
+        return blocked;
+    };
+
+    // Every straight flush window from Ace high down to the wheel
+    for high in (5..=14).rev() {
+        let window: Vec<Card> = (high - 4..=high)
+            .map(|value| Card { suit: suit.clone(), rank: rank_from_value(value) })
+            .collect();
+        let on_board = window.iter().filter(|card| board.contains(card)).count();
+
+        if on_board >= 3 && window.iter().any(|card| hole.contains(card)) {
+            blocked.push(format!(
+                "blocks the {}-high straight flush",
+                rank_label(&rank_from_value(high))
+            ));
+        }
+    }
+
+    if let Some(card) = top_live_card(&suit, board) {
+        if hole.contains(&card) {
+            blocked.push("blocks the nut flush".to_string());
+        }
+    }
+
+    blocked
+}
+
+// The suit with at least three cards on the board, if any
+fn board_flush_suit(board: &[Card]) -> Option<Suit> {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    suits
+        .into_iter()
+        .find(|suit| board.iter().filter(|card| &card.suit == suit).count() >= 3)
+}
+
+// Highest card of a suit that is not on the board
+fn top_live_card(suit: &Suit, board: &[Card]) -> Option<Card> {
+    sorted_by_rank(suit_sequence(suit.clone()))
+        .into_iter()
+        .find(|card| !board.contains(card))
+}
+
+// Drop the two-card combos from a range that need a card already known to be dead
+pub fn remove_blocked_combos(range: &[[Card; 2]], dead: &[Card]) -> Vec<[Card; 2]> {
+    range
+        .iter()
+        .filter(|combo| !combo.iter().any(|card| dead.contains(card)))
+        .cloned()
+        .collect()
+}
+
+// Rank gap between two hole cards: 0 for a pair, 1 for connectors like 9-10.
+// The Ace counts high or low, whichever is closer, so A-2 is connected.
+pub fn connectivity(cards: &[Card]) -> u8 {
+    if cards.len() != 2 {
+        panic!("Connectivity needs exactly 2 cards but got {}.", cards.len());
+    }
+
+    let a = rank_value(&cards[0].rank);
+    let b = rank_value(&cards[1].rank);
+    let gap = a.abs_diff(b);
+
+    if a == 14 || b == 14 {
+        gap.min(a.min(b) - 1)
+    } else {
+        gap
+    }
+}
+
+// True if all the cards share a suit
+pub fn is_suited(cards: &[Card]) -> bool {
+    cards.iter().all(|card| card.suit == cards[0].suit)
+}
+
+// Nickname for a famous hand. The Dead Man's Hand is the black aces and black
+// eights, with any other cards; the rest are two-card starting hands.
+pub fn named_hand(cards: &[Card]) -> Option<&'static str> {
+    let dead_mans_hand = [
+        Card { suit: Suit::Spades, rank: Rank::Ace },
+        Card { suit: Suit::Clubs, rank: Rank::Ace },
+        Card { suit: Suit::Spades, rank: Rank::Number(8) },
+        Card { suit: Suit::Clubs, rank: Rank::Number(8) },
+    ];
+    if dead_mans_hand.iter().all(|card| cards.contains(card)) {
+        return Some("Dead Man's Hand");
+    }
+
+    if cards.len() != 2 {
+        return None;
+    }
+
+    let mut values = [rank_value(&cards[0].rank), rank_value(&cards[1].rank)];
+    values.sort();
+
+    match values {
+        [14, 14] => Some("Pocket Rockets"),
+        [13, 13] => Some("Cowboys"),
+        [12, 12] => Some("Ladies"),
+        [11, 11] => Some("Fishhooks"),
+        [13, 14] => Some("Big Slick"),
+        [2, 10] => Some("Doyle Brunson"),
+        [2, 7] if !is_suited(cards) => Some("The Hammer"),
+        _ => None,
+    }
+}
+
+// How many cards of each rank remain, indexed from 2 (index 0) up to Ace (index 12).
+// Number ranks outside 2 through 10, like a numeric deck's Number(1), have no
+// bucket and are not counted.
+pub fn deck_rank_histogram(cards: &[Card]) -> [usize; 13] {
+    let mut histogram = [0; 13];
+    for card in cards {
+        let index = match card.rank {
+            Rank::Number(n) if !(2..=10).contains(&n) => continue,
+            _ => rank_value(&card.rank) as usize - 2,
+        };
+        histogram[index] += 1;
+    }
+    histogram
+}
+
+// True if exactly four of the cards share a suit. A made flush of five or more
+// is not a draw, so it returns false.
+pub fn is_flush_draw(cards: &[Card]) -> bool {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+    let counts: Vec<usize> = suits
+        .iter()
+        .map(|suit| cards.iter().filter(|card| &card.suit == suit).count())
+        .collect();
+
+    counts.contains(&4) && counts.iter().all(|&count| count < 5)
+}
+
+// True if the cards hold four consecutive ranks, with the Ace playing high or low.
+// A made straight is not a draw, so it returns false.
+pub fn is_straight_draw(cards: &[Card]) -> bool {
+    if find_straight(cards).is_some() {
+        return false;
+    }
+
+    let values = straight_values(cards);
+
+    (1..=11).any(|low| (low..low + 4).all(|v| values.contains(&v)))
+}
+
+// The five ranks of the highest straight in the cards, high to low, if there is one.
+// The wheel comes back as 5-4-3-2-A.
+pub fn find_straight(cards: &[Card]) -> Option<[Rank; 5]> {
+    let values = straight_values(cards);
+
+    let high = (5..=14)
+        .rev()
+        .find(|&high| (high - 4..=high).all(|v| values.contains(&v)))?;
+
+    Some([0, 1, 2, 3, 4].map(|step| rank_from_value(high - step)))
+}
+
+// Every unseen card that would give the hand a straight, each listed once even
+// when it fills more than one straight, as with a double gutshot. Cards in the
+// hand or in dead are never outs, and a hand that already has a straight has none.
+pub fn unique_straight_outs(hand: &[Card], dead: &[Card]) -> Vec<Card> {
+    if find_straight(hand).is_some() {
+        return Vec::new();
+    }
+
+    let mut seen = hand.to_vec();
+    seen.extend_from_slice(dead);
+
+    missing_cards(&seen)
+        .into_iter()
+        .filter(|card| {
+            let mut with_card = hand.to_vec();
+            with_card.push(card.clone());
+            find_straight(&with_card).is_some()
+        })
+        .collect()
+}
+
+// Rank values present in the cards, with an Ace counted as both 14 and 1
+fn straight_values(cards: &[Card]) -> HashSet<u8> {
+    let mut values: HashSet<u8> = cards.iter().map(|card| rank_value(&card.rank)).collect();
+    if values.contains(&14) {
+        values.insert(1);
+    }
+    values
+}
+
+// True if three or more cards are all one suit and consecutive in rank, in any
+// order, as Rummy requires for a meld. With ace_high the Ace sits above the King,
+// otherwise below the 2.
+pub fn is_run(cards: &[Card], ace_high: bool) -> bool {
+    if cards.len() < 3 {
+        return false;
+    }
+
+    let first = &cards[0];
+    if cards.iter().any(|card| card.suit != first.suit) {
+        return false;
+    }
+
+    let mut values: Vec<u8> = cards
+        .iter()
+        .map(|card| match card.rank {
+            Rank::Ace if !ace_high => 1,
+            _ => rank_value(&card.rank),
+        })
+        .collect();
+    values.sort();
+
+    values.windows(2).all(|pair| pair[1] == pair[0] + 1)
+}
+
+// True if three or more cards all share one rank, like a book in Rummy or Go Fish
+pub fn is_set(cards: &[Card]) -> bool {
+    cards.len() >= 3 && distinct_ranks(cards) == 1
+}
+
+// Sort cards from the highest rank to the lowest, Ace high, and hand them back
+pub fn sorted_by_rank(mut cards: Vec<Card>) -> Vec<Card> {
+    cards.sort_by_key(|card| std::cmp::Reverse(rank_value(&card.rank)));
+    cards
+}
+
+// Relabel suits so that hands differing only by a permutation of suits come out
+// identical. Suits are ordered by how many cards they hold, then by their ranks,
+// and renamed Spades, Hearts, Diamonds, Clubs in that order. Cards come back
+// grouped by their new suit and sorted high to low within it.
+pub fn canonical_hand(cards: &[Card]) -> Vec<Card> {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    let mut groups: Vec<Vec<Rank>> = suits
+        .iter()
+        .map(|suit| {
+            let mut ranks: Vec<Rank> = cards
+                .iter()
+                .filter(|card| &card.suit == suit)
+                .map(|card| card.rank.clone())
+                .collect();
+            ranks.sort_by_key(|rank| std::cmp::Reverse(rank_value(rank)));
+            ranks
+        })
+        .filter(|ranks| !ranks.is_empty())
+        .collect();
+
+    groups.sort_by(|a, b| {
+        let a_values: Vec<u8> = a.iter().map(rank_value).collect();
+        let b_values: Vec<u8> = b.iter().map(rank_value).collect();
+        b.len().cmp(&a.len()).then(b_values.cmp(&a_values))
+    });
+
+    groups
+        .into_iter()
+        .zip(suits)
+        .flat_map(|(ranks, suit)| {
+            ranks.into_iter().map(move |rank| Card { suit: suit.clone(), rank })
+        })
+        .collect()
+}
+
+// Take the top n cards off the deck, in the order they would be drawn.
+// Asking for more cards than the deck holds just takes them all.
+pub fn split_off_top(deck: &mut Vec<Card>, n: usize) -> Vec<Card> {
+    let n = n.min(deck.len());
+    let mut top = deck.split_off(deck.len() - n);
+    top.reverse();
+    top
+}
+
+// Take the bottom card instead of the top one that pop() would draw
+pub fn deal_from_bottom(deck: &mut Vec<Card>) -> Option<Card> {
+    if deck.is_empty() {
+        None
+    } else {
+        Some(deck.remove(0))
+    }
+}
+
+pub fn deal_hands(deck: &mut Vec<Card>, cards_per_hand: usize, num_hands: usize) -> Vec<Vec<Card>> {
+    deal_from_seat(deck, cards_per_hand, num_hands, 0, &mut rand::rng())
+}
+
+// Same as deal_hands but shuffles with the caller's rng instead of the thread-local one.
+// Every dealing function that shuffles has a _with variant like this; use those with
+// a seeded rng on wasm32-unknown-unknown, where the thread-local rng needs
+// getrandom's "wasm_js" backend to be configured first.
+pub fn deal_hands_with<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    rng: &mut R,
+) -> Vec<Vec<Card>> {
+    deal_from_seat(deck, cards_per_hand, num_hands, 0, rng)
+}
+
+// Deal as usual, then sort each hand high to low by rank for display
+pub fn deal_sorted(deck: &mut Vec<Card>, cards_per_hand: usize, num_hands: usize) -> Vec<Vec<Card>> {
+    deal_sorted_with(deck, cards_per_hand, num_hands, &mut rand::rng())
+}
+
+pub fn deal_sorted_with<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    rng: &mut R,
+) -> Vec<Vec<Card>> {
+    deal_hands_with(deck, cards_per_hand, num_hands, rng)
+        .into_iter()
+        .map(sorted_by_rank)
+        .collect()
+}
+
+// Deal like a live table: the first card goes to the seat left of the button
+pub fn deal_hands_from_button(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    button: usize,
+) -> Vec<Vec<Card>> {
+    deal_hands_from_button_with(deck, cards_per_hand, num_hands, button, &mut rand::rng())
+}
+
+pub fn deal_hands_from_button_with<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    button: usize,
+    rng: &mut R,
+) -> Vec<Vec<Card>> {
+    let first_seat = (button + 1).checked_rem(num_hands).unwrap_or(0);
+    deal_from_seat(deck, cards_per_hand, num_hands, first_seat, rng)
+}
+
+fn deal_from_seat<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    first_seat: usize,
+    rng: &mut R,
+) -> Vec<Vec<Card>> {
+    let total_needed = cards_per_hand * num_hands;
+    if total_needed > deck.len() {
+        panic!(
+            "Not enough cards in deck! Requested {} but only {} available.",
+            total_needed,
+            deck.len()
+        );
+    }
+
+    deck.shuffle(rng);
+    deal_in_order(deck, cards_per_hand, num_hands, first_seat)
+}
+
+// Deal the already shuffled deck round-robin off the top, starting at first_seat
+fn deal_in_order(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    first_seat: usize,
+) -> Vec<Vec<Card>> {
+    let mut hands = vec![Vec::new(); num_hands];
+
+    for i in 0..cards_per_hand * num_hands {
+        let card = deck.pop().unwrap();
+        hands[(first_seat + i) % num_hands].push(card);
+    }
+
+    hands
+}
+
+// Deal by weighted sampling without replacement, for stress-testing rare hands.
+// A card is picked in proportion to its rank's weight; ranks missing from the
+// map weigh 1.0, so equal weights give an ordinary uniform deal.
+pub fn deal_biased(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    weights: &HashMap<Rank, f64>,
+    seed: u64,
+) -> Vec<Vec<Card>> {
+    let total_needed = cards_per_hand * num_hands;
+    if total_needed > deck.len() {
+        panic!(
+            "Not enough cards in deck! Requested {} but only {} available.",
+            total_needed,
+            deck.len()
+        );
+    }
+
+    let weight = |card: &Card| weights.get(&card.rank).copied().unwrap_or(1.0).max(0.0);
+    let mut rng = seeded_rng(seed);
+
+    let mut hands = vec![Vec::new(); num_hands];
+
+    for i in 0..total_needed {
+        let total: f64 = deck.iter().map(weight).sum();
+        let mut target = rng.random::<f64>() * total;
+        let index = deck
+            .iter()
+            .position(|card| {
+                target -= weight(card);
+                target < 0.0
+            })
+            .unwrap_or(deck.len() - 1);
+
+        hands[i % num_hands].push(deck.remove(index));
+    }
+
+    hands
+}
+
+// Who may see a card on the table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    Public,         // community and face-up cards
+    Private(usize), // hole cards, seen only by this seat
+}
+
+#[derive(Debug, Clone)]
+pub struct TableCard {
+    pub card: Card,
+    pub visibility: Visibility,
+}
+
+// A table card as one seat sees it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Seen {
+    Card(Card),
+    Hidden(usize), // another seat's hole card, by owner, with no rank or suit
+}
+
+// The whole table from one seat, in table order. Its own hole cards and public
+// cards show; other players' hole cards stay in place as Seen::Hidden, so the
+// observer can still count them.
+pub fn observer_view(table: &[TableCard], observer: usize) -> Vec<Seen> {
+    table
+        .iter()
+        .map(|table_card| match table_card.visibility {
+            Visibility::Private(seat) if seat != observer => Seen::Hidden(seat),
+            _ => Seen::Card(table_card.card.clone()),
+        })
+        .collect()
+}
+
+// The cards a seat is allowed to see: its own hole cards plus everything public.
+// Other players' hole cards are left out; use observer_view to keep a
+// placeholder for them.
+pub fn visible_cards(table: &[TableCard], observer: usize) -> Vec<Card> {
+    observer_view(table, observer)
+        .into_iter()
+        .filter_map(|seen| match seen {
+            Seen::Card(card) => Some(card),
+            Seen::Hidden(_) => None,
+        })
+        .collect()
+}
+
+// A Hold'em deal: two hole cards per player, the board so far, and the undealt stock
+#[derive(Debug, Clone)]
+pub struct HoldemDeal {
+    pub hole_cards: Vec<Vec<Card>>,
+    pub community: Vec<Card>,
+    pub stock: Vec<Card>,
+}
+
+// The rng behind every seeded deal. The seed fills the first eight bytes of the
+// ChaCha8 key, little-endian, so the stream depends only on the seed and not on
+// how rand expands a u64 seed.
+pub(crate) fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    let mut key = [0; 32];
+    key[..8].copy_from_slice(&seed.to_le_bytes());
+    ChaCha8Rng::from_seed(key)
+}
+
+// Fisher-Yates shuffle drawing its indices straight from the rng's u64 output.
+// rand has changed SliceRandom::shuffle between versions, so seeded deals use
+// this instead to keep dealing the same cards for a seed.
+fn shuffle_seeded(deck: &mut [Card], rng: &mut ChaCha8Rng) {
+    for i in (1..deck.len()).rev() {
+        let j = draw_below(rng, i as u64 + 1);
+        deck.swap(i, j as usize);
+    }
+}
+
+// Uniform value in 0..bound, redrawing the uneven tail of u64 values
+fn draw_below(rng: &mut ChaCha8Rng, bound: u64) -> u64 {
+    let limit = u64::MAX - u64::MAX % bound;
+    loop {
+        let value = rng.next_u64();
+        if value < limit {
+            return value % bound;
+        }
+    }
+}
+
+// Deal hands from the seed's shuffle, round-robin from the first seat
+fn deal_seeded(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    seed: u64,
+) -> Vec<Vec<Card>> {
+    let total_needed = cards_per_hand * num_hands;
+    if total_needed > deck.len() {
+        panic!(
+            "Not enough cards in deck! Requested {} but only {} available.",
+            total_needed,
+            deck.len()
+        );
+    }
+
+    shuffle_seeded(deck, &mut seeded_rng(seed));
+    deal_in_order(deck, cards_per_hand, num_hands, 0)
+}
+
+// Deal Hold'em hole cards one at a time around the table from a seeded shuffle.
+// The board starts empty; flop, turn and river come from the stock.
+pub fn deal_holdem(num_players: usize, seed: u64) -> HoldemDeal {
+    let mut stock = generate_deck();
+    let hole_cards = deal_seeded(&mut stock, 2, num_players, seed);
+
+    HoldemDeal {
+        hole_cards,
+        community: Vec::new(),
+        stock,
+    }
+}
+
+// Deal seven-card stud: two down, four up, one down, one card per player each round
+pub fn deal_stud(deck: &mut Vec<Card>, num_players: usize) -> Vec<Vec<DealtCard>> {
+    deal_stud_with(deck, num_players, &mut rand::rng())
+}
+
+pub fn deal_stud_with<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    num_players: usize,
+    rng: &mut R,
+) -> Vec<Vec<DealtCard>> {
+    let streets = [false, false, true, true, true, true, false];
+
+    let total_needed = streets.len() * num_players;
+    if total_needed > deck.len() {
+        panic!(
+            "Not enough cards in deck! Requested {} but only {} available.",
+            total_needed,
+            deck.len()
+        );
+    }
+
+    deck.shuffle(rng);
+
+    let mut hands = vec![Vec::new(); num_players];
+
+    for face_up in streets {
+        for hand in hands.iter_mut() {
+            let card = deck.pop().unwrap();
+            hand.push(DealtCard { card, face_up });
+        }
+    }
+
+    hands
+}
+
+// Split cards into hands of cards_per_hand taken in sequence, not round-robin.
+// Leftover cards that cannot fill a whole hand are dropped, so hands of zero
+// cards give no hands at all.
+pub fn chunk_into_hands(cards: Vec<Card>, cards_per_hand: usize) -> Vec<Vec<Card>> {
+    if cards_per_hand == 0 {
+        return Vec::new();
+    }
+
+    cards
+        .chunks_exact(cards_per_hand)
+        .map(|hand| hand.to_vec())
+        .collect()
+}
+
+// How many cards of each suit every hand holds, listing all four suits
+pub fn suit_counts_per_hand(hands: &[Vec<Card>]) -> Vec<HashMap<Suit, usize>> {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    hands
+        .iter()
+        .map(|hand| {
+            suits
+                .iter()
+                .map(|suit| {
+                    let count = hand.iter().filter(|card| &card.suit == suit).count();
+                    (suit.clone(), count)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Combine several hands or piles into one, keeping their order
+pub fn merge_hands(hands: Vec<Vec<Card>>) -> Vec<Card> {
+    hands.into_iter().flatten().collect()
+}
+
+// Shuffle only the cards in positions start..end, leaving the rest where they are
+pub fn shuffle_range<R: Rng + ?Sized>(deck: &mut [Card], start: usize, end: usize, rng: &mut R) {
+    if start > end || end > deck.len() {
+        panic!(
+            "Invalid shuffle range {}..{} for a deck of {} cards.",
+            start,
+            end,
+            deck.len()
+        );
+    }
+
+    deck[start..end].shuffle(rng);
+}
+
+// Share of the final pot a call puts in, which is the equity needed to break even
+pub fn pot_odds(pot: u32, to_call: u32) -> f64 {
+    if to_call == 0 {
+        return 0.0;
+    }
+    to_call as f64 / (pot as f64 + to_call as f64)
+}
+
+// True if calling is profitable: the chance of winning beats the pot odds
+pub fn should_call(pot: u32, to_call: u32, win_prob: f64) -> bool {
+    win_prob > pot_odds(pot, to_call)
+}
+
+// Read a deck order saved by save_deck, or any whitespace-separated card list
+pub fn load_deck(reader: impl Read) -> Result<Vec<Card>, CardParseError> {
+    let mut cards = Vec::new();
+
+    for line in BufReader::new(reader).lines() {
+        for token in line?.split_whitespace() {
+            cards.push(token.parse()?);
+        }
+    }
+
+    Ok(cards)
+}
+
+// Write a deck order one card per line, in the form display_card uses
+pub fn save_deck(mut writer: impl Write, cards: &[Card]) -> std::io::Result<()> {
+    for card in cards {
+        writeln!(writer, "{}", display_card(card))?;
+    }
+    Ok(())
+}
+
+// Put a card into the deck with `position` cards above it, so 0 makes it the next
+// card drawn. Positions count down from the top, the end that cards are popped from.
+pub fn insert_at(deck: &mut Vec<Card>, position: usize, card: Card) {
+    if position > deck.len() {
+        panic!(
+            "Cannot insert at position {} in a deck of {} cards.",
+            position,
+            deck.len()
+        );
+    }
+
+    let index = deck.len() - position;
+    deck.insert(index, card);
+}
+
+// Take the card `position` cards down from the top and put it on top
+pub fn move_to_top(deck: &mut Vec<Card>, position: usize) {
+    if position >= deck.len() {
+        panic!(
+            "Cannot move position {} in a deck of {} cards.",
+            position,
+            deck.len()
+        );
+    }
+
+    let card = deck.remove(deck.len() - 1 - position);
+    deck.push(card);
+}
+
+// Hash of the cards in order, so two shuffles of the same deck hash differently.
+// Stable within a build, but not across Rust releases, so don't persist it.
+pub fn deck_hash(cards: &[Card]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    cards.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Hash of which cards are present, ignoring their order: any shuffle of the
+// same cards gives the same value. Same stability caveat as deck_hash.
+pub fn multiset_hash(cards: &[Card]) -> u64 {
+    let mut card_hashes: Vec<u64> = cards
+        .iter()
+        .map(|card| {
+            let mut hasher = DefaultHasher::new();
+            card.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect();
+    card_hashes.sort();
+
+    let mut hasher = DefaultHasher::new();
+    card_hashes.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Which cards a game deals from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckKind {
+    Standard,
+    Shoe(usize),  // this many standard decks mixed together
+    Stripped(u8), // only Aces and ranks of this value and up, e.g. 9 for Euchre
+}
+
+impl DeckKind {
+    pub fn cards(&self) -> Vec<Card> {
+        match self {
+            DeckKind::Standard => generate_deck(),
+            DeckKind::Shoe(num_decks) => (0..*num_decks).flat_map(|_| generate_deck()).collect(),
+            DeckKind::Stripped(lowest) => build_deck(|rank, _| rank_value(rank) >= *lowest),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DealError {
+    NotEnoughCards { requested: usize, available: usize },
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DealError::NotEnoughCards { requested, available } => write!(
+                f,
+                "Not enough cards in deck! Requested {} but only {} available.",
+                requested, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DealError {}
+
+// Everything needed to deal a game, built up with chained setters:
+// GameConfig::new(4, 5).deck(DeckKind::Stripped(9)).seed(7)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameConfig {
+    pub num_players: usize,
+    pub cards_per_hand: usize,
+    pub deck: DeckKind,
+    pub seed: Option<u64>, // None shuffles with the thread-local rng
+}
+
+impl GameConfig {
+    pub fn new(num_players: usize, cards_per_hand: usize) -> Self {
+        GameConfig {
+            num_players,
+            cards_per_hand,
+            deck: DeckKind::Standard,
+            seed: None,
+        }
+    }
+
+    pub fn deck(mut self, deck: DeckKind) -> Self {
+        self.deck = deck;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    // Shuffle the configured deck and deal every player a hand
+    pub fn deal(&self) -> Result<Vec<Vec<Card>>, DealError> {
+        let mut deck = self.deck.cards();
+
+        let requested = self.cards_per_hand * self.num_players;
+        if requested > deck.len() {
+            return Err(DealError::NotEnoughCards {
+                requested,
+                available: deck.len(),
+            });
+        }
+
+        let hands = match self.seed {
+            Some(seed) => deal_seeded(&mut deck, self.cards_per_hand, self.num_players, seed),
+            None => deal_hands(&mut deck, self.cards_per_hand, self.num_players),
+        };
+        Ok(hands)
+    }
+}
+
+// Reproduce exactly the hands a seeded config deals. Seeded configs shuffle with
+// this crate's own Fisher-Yates over a ChaCha8 stream and deal in a fixed order,
+// so the same seed gives the same hands on any platform and rand version.
+// Panics if the config has no seed or its deck runs short.
+pub fn replay(config: &GameConfig) -> Vec<Vec<Card>> {
+    if config.seed.is_none() {
+        panic!("Cannot replay a GameConfig that has no seed.");
+    }
+
+    match config.deal() {
+        Ok(hands) => hands,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+// Scoreboard across several deals. Every winner of a deal gets a win, so a
+// split pot counts for each player in it.
+#[derive(Debug, Clone)]
+pub struct Session {
+    wins: Vec<u32>,
+}
+
+impl Session {
+    pub fn new(num_players: usize) -> Self {
+        Session { wins: vec![0; num_players] }
+    }
+
+    // Give each winner a win. A seat outside the session, or one listed twice,
+    // panics before any win is recorded.
+    pub fn record(&mut self, winners: &[usize]) {
+        if let Some(&player) = winners.iter().find(|&&player| player >= self.wins.len()) {
+            panic!(
+                "No player {} in a session of {} players.",
+                player,
+                self.wins.len()
+            );
+        }
+
+        let mut seen = HashSet::new();
+        if let Some(&player) = winners.iter().find(|&&player| !seen.insert(player)) {
+            panic!("Player {} is listed as a winner more than once.", player);
+        }
+
+        for &player in winners {
+            self.wins[player] += 1;
+        }
+    }
+
+    // (player, wins) from the most wins down, lower seats first on ties
+    pub fn standings(&self) -> Vec<(usize, u32)> {
+        let mut standings: Vec<(usize, u32)> = self.wins.iter().copied().enumerate().collect();
+        standings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        standings
+    }
+}
+
+// Deal the hands as deal_hands does, then set the next kitty_size cards aside,
+// such as the four-card kitty in Euchre
+pub fn deal_with_kitty(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    kitty_size: usize,
+) -> Result<(Vec<Vec<Card>>, Vec<Card>), DealError> {
+    deal_with_kitty_with(deck, cards_per_hand, num_hands, kitty_size, &mut rand::rng())
+}
+
+pub fn deal_with_kitty_with<R: Rng + ?Sized>(
+    deck: &mut Vec<Card>,
+    cards_per_hand: usize,
+    num_hands: usize,
+    kitty_size: usize,
+    rng: &mut R,
+) -> Result<(Vec<Vec<Card>>, Vec<Card>), DealError> {
+    let requested = cards_per_hand * num_hands + kitty_size;
+    if requested > deck.len() {
+        return Err(DealError::NotEnoughCards {
+            requested,
+            available: deck.len(),
+        });
+    }
+
+    let hands = deal_hands_with(deck, cards_per_hand, num_hands, rng);
+    let kitty = split_off_top(deck, kitty_size);
+    Ok((hands, kitty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Vec<Card> {
+        cards.split_whitespace().map(|card| card.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn card_round_trips_through_rank_suit_tuple() {
+        let card: Card = (Rank::Queen, Suit::Diamonds).into();
+        assert_eq!(card, Card { suit: Suit::Diamonds, rank: Rank::Queen });
+
+        for card in hand("A♠ 10♥ QD 2c") {
+            let (rank, suit): (Rank, Suit) = card.clone().into();
+            assert_eq!(Card::from((rank, suit)), card);
+        }
+    }
+
+    #[test]
+    fn stud_deal_marks_two_down_four_up_one_down() {
+        let mut deck = generate_deck();
+        let hands = deal_stud(&mut deck, 4);
+
+        assert_eq!(deck.len(), 52 - 28);
+        for hand in &hands {
+            let face_up: Vec<bool> = hand.iter().map(|dealt| dealt.face_up).collect();
+            assert_eq!(face_up, [false, false, true, true, true, true, false]);
+        }
+    }
+
+    #[test]
+    fn distinct_suits_and_ranks() {
+        assert_eq!(distinct_suits(&hand("AS KH 4C 9D")), 4);
+        assert_eq!(distinct_ranks(&hand("AS AH AC 9D 9C")), 2);
+    }
+
+    #[test]
+    fn numeric_deck_of_one_through_six() {
+        let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+        let deck = generate_numeric_deck(6, &suits);
+
+        assert_eq!(deck.len(), 24);
+        assert!(deck
+            .iter()
+            .all(|card| matches!(card.rank, Rank::Number(1..=6))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Numeric decks go up to 10")]
+    fn numeric_deck_rejects_ranks_above_ten() {
+        generate_numeric_deck(11, &[Suit::Hearts]);
+    }
+
+    #[test]
+    fn seeded_rng_gives_the_same_deal() {
+        let deal = |seed| {
+            let mut deck = generate_deck();
+            deal_hands_with(&mut deck, 5, 4, &mut seeded_rng(seed))
+        };
+
+        assert_eq!(deal(7), deal(7));
+        assert_ne!(deal(7), deal(8));
+    }
+
+    #[test]
+    fn moving_the_button_moves_the_first_card() {
+        let deal = |button| {
+            let mut deck = generate_deck();
+            deal_hands_from_button_with(&mut deck, 2, 4, button, &mut seeded_rng(3))
+        };
+
+        let first = deal(0);
+        let moved = deal(1);
+        assert_eq!(first[1][0], moved[2][0]);
+        assert_eq!(first[0][0], moved[1][0]);
+    }
+
+    #[test]
+    fn dealing_from_the_button_to_no_hands() {
+        let mut deck = generate_deck();
+        assert!(deal_hands_from_button(&mut deck, 2, 0, 3).is_empty());
+    }
+
+    #[test]
+    fn four_hearts_is_a_flush_draw() {
+        assert!(is_flush_draw(&hand("2H 7H 9H KH 3C")));
+        assert!(!is_flush_draw(&hand("2H 7H 9H KH 3H")));
+        assert!(!is_flush_draw(&hand("2H 7H 9S KH 3C")));
+    }
+
+    #[test]
+    fn four_to_seven_is_a_straight_draw() {
+        assert!(is_straight_draw(&hand("4S 5H 6D 7C KS")));
+        assert!(is_straight_draw(&hand("AS 2H 3D 4C 9S")));
+        assert!(!is_straight_draw(&hand("4S 5H 6D 7C 8S")));
+        assert!(!is_straight_draw(&hand("4S 5H 6D 8C KS")));
+    }
+
+    #[test]
+    fn merging_two_halves_gives_a_full_deck() {
+        let mut deck = generate_deck();
+        let bottom = deck.split_off(26);
+
+        let merged = merge_hands(vec![deck, bottom]);
+        assert_eq!(merged.len(), 52);
+        assert_eq!(merged, generate_deck());
+    }
+
+    #[test]
+    fn suit_sequence_is_one_suit_ascending() {
+        let cards = suit_sequence(Suit::Clubs);
+
+        assert_eq!(cards.len(), 13);
+        assert!(cards.iter().all(|card| card.suit == Suit::Clubs));
+        assert_eq!(cards[0].rank, Rank::Ace);
+        assert!(cards[1..]
+            .windows(2)
+            .all(|pair| rank_value(&pair[0].rank) + 1 == rank_value(&pair[1].rank)));
+    }
+
+    #[test]
+    fn five_six_seven_of_hearts_is_a_run() {
+        assert!(is_run(&hand("7H 5H 6H"), true));
+        assert!(is_run(&hand("AS 2S 3S"), false));
+        assert!(is_run(&hand("QS KS AS"), true));
+    }
+
+    #[test]
+    fn off_suit_gapped_or_short_cards_are_not_runs() {
+        assert!(!is_run(&hand("5H 6S 7H"), true));
+        assert!(!is_run(&hand("5H 6H 8H"), true));
+        assert!(!is_run(&hand("5H 6H"), true));
+    }
+
+    #[test]
+    fn three_kings_are_a_set() {
+        assert!(is_set(&hand("KS KH KD")));
+        assert!(!is_set(&hand("KS KH")));
+        assert!(!is_set(&hand("KS KH QD")));
+    }
+
+    #[test]
+    fn card_art_shows_the_rank_in_both_corners() {
+        let art = render_card_art(&"Q♦".parse().unwrap());
+
+        assert_eq!(art.len(), 5);
+        assert_eq!(art[1], "│Q  │");
+        assert_eq!(art[2], "│ ♦ │");
+        assert_eq!(art[3], "│  Q│");
+    }
+
+    #[test]
+    fn card_art_lines_up_a_ten() {
+        let ten = render_card_art(&"10♠".parse().unwrap());
+        let ace = render_card_art(&"A♠".parse().unwrap());
+
+        assert_eq!(ten[1], "│10 │");
+        assert_eq!(ten[3], "│ 10│");
+        for (ten_line, ace_line) in ten.iter().zip(&ace) {
+            assert_eq!(ten_line.chars().count(), ace_line.chars().count());
+        }
+    }
+
+    #[test]
+    fn shuffle_range_leaves_the_rest_in_place() {
+        let original = generate_deck();
+        let mut deck = original.clone();
+        shuffle_range(&mut deck, 10, 20, &mut seeded_rng(5));
+
+        assert_eq!(deck[..10], original[..10]);
+        assert_eq!(deck[20..], original[20..]);
+        assert_ne!(deck[10..20], original[10..20]);
+
+        let mut middle = deck[10..20].to_vec();
+        middle.sort_by_key(|card| (card.suit.name(), rank_value(&card.rank)));
+        let mut expected = original[10..20].to_vec();
+        expected.sort_by_key(|card| (card.suit.name(), rank_value(&card.rank)));
+        assert_eq!(middle, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid shuffle range")]
+    fn shuffle_range_rejects_a_range_past_the_end() {
+        let mut deck = generate_deck();
+        shuffle_range(&mut deck, 40, 60, &mut rand::rng());
+    }
+
+    #[test]
+    fn isomorphic_flushes_canonicalize_identically() {
+        let hearts = canonical_hand(&hand("AH KH 9H 5H 2H"));
+        let clubs = canonical_hand(&hand("5C AC 2C 9C KC"));
+
+        assert_eq!(hearts, clubs);
+        assert_eq!(hearts, hand("AS KS 9S 5S 2S"));
+    }
+
+    #[test]
+    fn holdem_deal_is_reproducible_from_its_seed() {
+        let deal = deal_holdem(6, 42);
+
+        assert_eq!(deal.hole_cards.len(), 6);
+        assert!(deal.hole_cards.iter().all(|hole| hole.len() == 2));
+        assert!(deal.community.is_empty());
+        assert_eq!(deal.stock.len(), 52 - 12);
+
+        let mut all = merge_hands(deal.hole_cards.clone());
+        all.extend(deal.stock.iter().cloned());
+        assert_eq!(validate_unique(&all), Ok(()));
+
+        assert_eq!(deal_holdem(6, 42).hole_cards, deal.hole_cards);
+        assert_ne!(deal_holdem(6, 43).hole_cards, deal.hole_cards);
+    }
+
+    #[test]
+    fn sorted_by_rank_runs_high_to_low() {
+        let sorted = sorted_by_rank(hand("4C AS 10H 2D KC"));
+        assert_eq!(sorted, hand("AS KC 10H 4C 2D"));
+    }
+
+    #[test]
+    fn find_straight_sees_the_wheel() {
+        let wheel = [
+            Rank::Number(5),
+            Rank::Number(4),
+            Rank::Number(3),
+            Rank::Number(2),
+            Rank::Ace,
+        ];
+        assert_eq!(find_straight(&hand("AS 2H 3D 4C 5S 9H KD")), Some(wheel));
+    }
+
+    #[test]
+    fn find_straight_takes_the_highest_straight() {
+        let seven_to_jack = [
+            Rank::Jack,
+            Rank::Number(10),
+            Rank::Number(9),
+            Rank::Number(8),
+            Rank::Number(7),
+        ];
+        assert_eq!(find_straight(&hand("5S 6H 7D 8C 9S 10H JD")), Some(seven_to_jack));
+        assert_eq!(find_straight(&hand("5S 6H 7D 8C 10H")), None);
+    }
+
+    #[test]
+    fn full_house_groups_trips_before_the_pair() {
+        let groups = rank_groups(&hand("9S KH 9D KC 9C"));
+        assert_eq!(groups, vec![(Rank::Number(9), 3), (Rank::King, 2)]);
+    }
+
+    #[test]
+    fn shoe_deals_each_card_once_per_deck() {
+        let mut shoe = Shoe::new_with(6, &mut seeded_rng(9));
+        assert_eq!(shoe.remaining(), 6 * 52);
+
+        let mut counts: HashMap<Card, usize> = HashMap::new();
+        while let Some(card) = shoe.draw() {
+            *counts.entry(card).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.len(), 52);
+        assert!(counts.values().all(|&count| count == 6));
+        assert_eq!(shoe.draw(), None);
+        assert_eq!(shoe.remaining(), 0);
+    }
+
+    #[test]
+    fn missing_cards_skips_removed_and_duplicated_cards() {
+        let removed = hand("AS KH 4C 9D 2S AS KH");
+        let missing = missing_cards(&removed);
+
+        assert_eq!(missing.len(), 47);
+        assert!(removed.iter().all(|card| !missing.contains(card)));
+    }
+
+    #[test]
+    fn heavy_ace_weight_deals_more_aces() {
+        let aces_dealt = |weights: &HashMap<Rank, f64>| -> usize {
+            (0..200)
+                .map(|seed| {
+                    let mut deck = generate_deck();
+                    let hands = deal_biased(&mut deck, 5, 1, weights, seed);
+                    hands[0].iter().filter(|card| card.rank == Rank::Ace).count()
+                })
+                .sum()
+        };
+
+        let uniform = aces_dealt(&HashMap::new());
+        let heavy = aces_dealt(&HashMap::from([(Rank::Ace, 20.0)]));
+        assert!(heavy > uniform * 3, "{} aces weighted vs {} uniform", heavy, uniform);
+    }
+
+    #[test]
+    fn chunking_a_deck_into_four_hands() {
+        let hands = chunk_into_hands(generate_deck(), 13);
+
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 13));
+        assert_eq!(hands[0], suit_sequence(Suit::Spades));
+        assert!(chunk_into_hands(generate_deck(), 0).is_empty());
+    }
+
+    #[test]
+    fn call_with_good_odds_and_fold_with_bad() {
+        // Calling 10 into 90 needs 10% equity
+        assert!((pot_odds(90, 10) - 0.1).abs() < 1e-9);
+        assert!(should_call(90, 10, 0.35));
+        assert!(!should_call(50, 50, 0.2));
+    }
+
+    #[test]
+    fn saved_deck_loads_back_in_order() {
+        let mut deck = generate_deck();
+        deck.shuffle(&mut seeded_rng(11));
+
+        let mut buffer = Vec::new();
+        save_deck(&mut buffer, &deck).unwrap();
+
+        assert_eq!(load_deck(buffer.as_slice()).unwrap(), deck);
+    }
+
+    #[test]
+    fn loading_a_bad_token_is_an_error() {
+        let result = load_deck("A♠ K♥\nZZ 2♣\n".as_bytes());
+        assert!(matches!(result, Err(CardParseError::InvalidCard(token)) if token == "ZZ"));
+
+        for token in ["+5S", "05H", "010D", "0C", "11S"] {
+            let result = load_deck(token.as_bytes());
+            assert!(
+                matches!(&result, Err(CardParseError::InvalidCard(bad)) if bad == token),
+                "{} loaded as {:?}",
+                token,
+                result.ok()
+            );
+        }
+    }
+
+    #[test]
+    fn hidden_cards_show_face_down() {
+        let cards = hand("A♠ 10♥ K♣");
+
+        assert_eq!(display_hand_hidden(&cards, &[1]), "A♠ [##] K♣");
+        assert_eq!(display_hand_hidden(&cards, &[]), "A♠ 10♥ K♣");
+    }
+
+    #[test]
+    fn inserted_card_is_drawn_in_its_spot() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let mut deck = generate_deck();
+        deck.retain(|card| card != &ace);
+        insert_at(&mut deck, 10, ace.clone());
+
+        for _ in 0..10 {
+            assert_ne!(deck.pop(), Some(ace.clone()));
+        }
+        assert_eq!(deck.pop(), Some(ace));
+    }
+
+    #[test]
+    fn fanned_deck_shows_every_rank() {
+        let fanned = render_deck_fanned(&suit_sequence(Suit::Hearts), false);
+
+        for rank in ["A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K"] {
+            assert!(fanned.contains(rank), "{} missing from\n{}", rank, fanned);
+        }
+        assert_eq!(fanned.lines().count(), 5);
+        assert_eq!(fanned.matches('♥').count(), 13);
+    }
+
+    #[test]
+    fn doubled_ace_of_spades_is_reported() {
+        let mut deck = generate_deck();
+        assert_eq!(validate_unique(&deck), Ok(()));
+
+        deck.push(Card::new(Rank::Ace, Suit::Spades));
+        assert_eq!(validate_unique(&deck), Err(vec![Card::new(Rank::Ace, Suit::Spades)]));
+    }
+
+    #[test]
+    fn suit_names_and_symbols() {
+        let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+        assert_eq!(suits.each_ref().map(|suit| suit.name()), ["Spades", "Hearts", "Diamonds", "Clubs"]);
+        assert_eq!(suits.map(|suit| suit.symbol()), ['♠', '♥', '♦', '♣']);
+    }
+
+    #[test]
+    fn nine_ten_offsuit_is_connected() {
+        let nine_ten = hand("9S 10H");
+        assert_eq!(connectivity(&nine_ten), 1);
+        assert!(!is_suited(&nine_ten));
+
+        assert_eq!(connectivity(&hand("AS AH")), 0);
+        assert_eq!(connectivity(&hand("AS 2S")), 1);
+        assert!(is_suited(&hand("AS 2S")));
+    }
+
+    #[test]
+    fn reordering_keeps_the_multiset_hash_only() {
+        let deck = generate_deck();
+        let mut reordered = deck.clone();
+        reordered.swap(0, 51);
+
+        assert_eq!(multiset_hash(&deck), multiset_hash(&reordered));
+        assert_ne!(deck_hash(&deck), deck_hash(&reordered));
+        assert_ne!(multiset_hash(&deck), multiset_hash(&deck[1..]));
+    }
+
+    #[test]
+    fn sorted_deal_gives_descending_hands() {
+        let mut deck = generate_deck();
+        let hands = deal_sorted_with(&mut deck, 7, 4, &mut seeded_rng(2));
+
+        assert_eq!(hands.len(), 4);
+        for hand in hands {
+            assert_eq!(hand.len(), 7);
+            assert!(hand
+                .windows(2)
+                .all(|pair| rank_value(&pair[0].rank) >= rank_value(&pair[1].rank)));
+        }
+    }
+
+    #[test]
+    fn full_deck_grid_is_all_present() {
+        let grid = render_deck_grid(&generate_deck());
+        let rows: Vec<&str> = grid.lines().collect();
+
+        assert_eq!(rows.len(), 5);
+        assert!(rows[0].ends_with(" 10  J  Q  K"));
+        assert_eq!(grid.matches('#').count(), 52);
+        assert!(!grid.contains('.'));
+
+        let missing_ace = missing_cards(&[Card::new(Rank::Ace, Suit::Hearts)]);
+        assert!(render_deck_grid(&missing_ace).lines().nth(2).unwrap().starts_with("♥  ."));
+    }
+
+    #[test]
+    fn full_deal_holds_thirteen_of_each_suit() {
+        let mut deck = generate_deck();
+        let hands = deal_hands_with(&mut deck, 13, 4, &mut seeded_rng(4));
+        let counts = suit_counts_per_hand(&hands);
+
+        assert_eq!(counts.len(), 4);
+        for suit in [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs] {
+            assert_eq!(counts.iter().map(|hand| hand[&suit]).sum::<usize>(), 13);
+        }
+        assert!(counts.iter().all(|hand| hand.values().sum::<usize>() == 13));
+    }
+
+    #[test]
+    fn split_off_top_takes_cards_in_draw_order() {
+        let mut deck = generate_deck();
+        let mut expected = generate_deck();
+        let expected: Vec<Card> = (0..5).map(|_| expected.pop().unwrap()).collect();
+
+        assert_eq!(split_off_top(&mut deck, 5), expected);
+        assert_eq!(deck.len(), 47);
+    }
+
+    #[test]
+    fn split_off_top_clamps_to_the_deck() {
+        let mut deck = hand("AS KH");
+
+        assert_eq!(split_off_top(&mut deck, 5), hand("KH AS"));
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn black_aces_and_eights_are_the_dead_mans_hand() {
+        assert_eq!(named_hand(&hand("AS 8C AC 8S 9D")), Some("Dead Man's Hand"));
+        assert_eq!(named_hand(&hand("AS 8C AH 8S 9D")), None);
+        assert_eq!(named_hand(&hand("AS KD")), Some("Big Slick"));
+    }
+
+    #[test]
+    fn stripped_deck_config_deals() {
+        let config = GameConfig::new(4, 5).deck(DeckKind::Stripped(9)).seed(7);
+        let hands = config.deal().unwrap();
+
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 5));
+        assert!(hands
+            .iter()
+            .flatten()
+            .all(|card| rank_value(&card.rank) >= 9));
+    }
+
+    #[test]
+    fn config_reports_a_short_deck() {
+        let config = GameConfig::new(5, 5).deck(DeckKind::Stripped(9));
+
+        assert_eq!(
+            config.deal(),
+            Err(DealError::NotEnoughCards { requested: 25, available: 24 })
+        );
+    }
+
+    #[test]
+    fn one_card_runouts_cover_every_unknown_card() {
+        let known = hand("AS KH 4C 9D 2S");
+        let river: Vec<Vec<Card>> = runouts(&known, 1).collect();
+
+        assert_eq!(river.len(), 47);
+        assert!(river.iter().all(|runout| runout.len() == 1 && !known.contains(&runout[0])));
+        assert_eq!(runouts(&known, 2).count(), 47 * 46 / 2);
+    }
+
+    #[test]
+    fn opponents_hole_cards_are_withheld() {
+        let card = |text: &str, visibility| TableCard {
+            card: text.parse().unwrap(),
+            visibility,
+        };
+        let table = [
+            card("AS", Visibility::Private(0)),
+            card("KS", Visibility::Private(0)),
+            card("7H", Visibility::Private(1)),
+            card("7D", Visibility::Private(1)),
+            card("QC", Visibility::Public),
+        ];
+
+        assert_eq!(visible_cards(&table, 0), hand("AS KS QC"));
+        assert_eq!(visible_cards(&table, 1), hand("7H 7D QC"));
+        assert_eq!(visible_cards(&table, 2), hand("QC"));
+
+        let queen = Seen::Card("QC".parse().unwrap());
+        assert_eq!(
+            observer_view(&table, 1),
+            vec![
+                Seen::Hidden(0),
+                Seen::Hidden(0),
+                Seen::Card("7H".parse().unwrap()),
+                Seen::Card("7D".parse().unwrap()),
+                queen.clone(),
+            ]
+        );
+        assert_eq!(
+            observer_view(&table, 2),
+            vec![Seen::Hidden(0), Seen::Hidden(0), Seen::Hidden(1), Seen::Hidden(1), queen]
+        );
+    }
+
+    #[test]
+    fn const_cards_fill_a_static_array() {
+        static ROYAL_FLUSH: [Card; 5] = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Queen, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Spades),
+            Card::new(Rank::Number(10), Suit::Spades),
+        ];
+
+        assert_eq!(ROYAL_FLUSH.to_vec(), hand("AS KS QS JS 10S"));
+    }
+
+    #[test]
+    fn replay_matches_the_seeded_deal() {
+        let config = GameConfig::new(4, 5).seed(2024);
+
+        assert_eq!(replay(&config), replay(&config));
+        assert_eq!(replay(&config), config.deal().unwrap());
+        assert_ne!(replay(&config), replay(&config.clone().seed(2025)));
+    }
+
+    fn hole(cards: &str) -> [Card; 2] {
+        hand(cards).try_into().unwrap()
+    }
+
+    #[test]
+    fn ace_of_the_suit_makes_the_nut_flush() {
+        let board = hand("2H 7H 9H KC 4S");
+
+        assert!(is_nut_flush(&hole("AH 3H"), &board));
+        assert!(!is_nut_flush(&hole("KH 3H"), &board));
+        assert!(is_nut_flush(&hole("KH 3H"), &hand("2H 7H 9H AH 4S")));
+    }
+
+    #[test]
+    fn deal_from_bottom_takes_the_other_end() {
+        let mut deck = hand("AS KH 4C");
+
+        assert_eq!(deal_from_bottom(&mut deck), Some(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!(deck.pop(), Some(Card::new(Rank::Number(4), Suit::Clubs)));
+        assert_eq!(deal_from_bottom(&mut deck), Some(Card::new(Rank::King, Suit::Hearts)));
+        assert_eq!(deal_from_bottom(&mut deck), None);
+    }
+
+    #[test]
+    fn ace_of_hearts_blocks_the_nut_flush() {
+        let board = hand("2H 7H 9H KC 4S");
+
+        assert_eq!(blockers(&hole("AH 5C"), &board), vec!["blocks the nut flush"]);
+        assert!(blockers(&hole("QS 5C"), &board).is_empty());
+    }
+
+    #[test]
+    fn standings_run_from_most_wins() {
+        let mut session = Session::new(3);
+        session.record(&[2]);
+        session.record(&[0, 2]);
+        session.record(&[1]);
+
+        assert_eq!(session.standings(), vec![(2, 2), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No player 3 in a session of 3 players.")]
+    fn recording_an_unknown_seat_panics() {
+        Session::new(3).record(&[0, 3]);
+    }
+
+    #[test]
+    fn dead_ace_of_spades_removes_its_combos() {
+        let range = [
+            hole("AS KS"),
+            hole("AS AH"),
+            hole("KH KD"),
+            hole("QS JS"),
+        ];
+        let live = remove_blocked_combos(&range, &hand("AS"));
+
+        assert_eq!(live, vec![hole("KH KD"), hole("QS JS")]);
+    }
+
+    #[test]
+    fn glyphs_skip_the_knight() {
+        let glyph = |text: &str| display_card_glyph(&text.parse().unwrap()).unwrap() as u32;
+
+        assert_eq!(glyph("AS"), 0x1F0A1);
+        assert_eq!(glyph("10H"), 0x1F0BA);
+        assert_eq!(glyph("JD"), 0x1F0CB);
+        assert_eq!(glyph("QD"), 0x1F0CD);
+        assert_eq!(glyph("KC"), 0x1F0DE);
+    }
+
+    #[test]
+    fn fresh_deck_is_sorted_and_a_shuffled_one_is_not() {
+        let mut deck = generate_deck();
+        assert!(is_sorted_standard(&deck));
+
+        deck.shuffle(&mut seeded_rng(1));
+        assert!(!is_sorted_standard(&deck));
+        assert!(!is_sorted_standard(&generate_deck()[1..]));
+    }
+
+    #[test]
+    fn red_only_deck_has_26_cards() {
+        let red = build_deck(|_, suit| matches!(suit, Suit::Hearts | Suit::Diamonds));
+
+        assert_eq!(red.len(), 26);
+        assert!(red.iter().all(|card| card.suit == Suit::Hearts || card.suit == Suit::Diamonds));
+        assert_eq!(red[..13], suit_sequence(Suit::Hearts)[..]);
+    }
+
+    #[test]
+    fn double_gutshot_has_eight_outs() {
+        let outs = unique_straight_outs(&hand("5S 7H 8D 9C JS"), &[]);
+
+        assert_eq!(outs.len(), 8);
+        assert!(outs
+            .iter()
+            .all(|card| matches!(card.rank, Rank::Number(6) | Rank::Number(10))));
+        assert_eq!(unique_straight_outs(&hand("5S 7H 8D 9C JS"), &hand("6H 10D")).len(), 6);
+    }
+
+    #[test]
+    fn euchre_deal_leaves_a_four_card_kitty() {
+        let mut deck = DeckKind::Stripped(9).cards();
+        assert_eq!(deck.len(), 24);
+
+        let (hands, kitty) = deal_with_kitty_with(&mut deck, 5, 4, 4, &mut seeded_rng(6)).unwrap();
+
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 5));
+        assert_eq!(kitty.len(), 4);
+        assert!(deck.is_empty());
+
+        let mut all = merge_hands(hands);
+        all.extend(kitty);
+        assert_eq!(validate_unique(&all), Ok(()));
+    }
+
+    #[test]
+    fn full_deck_histogram_has_four_of_each_rank() {
+        let mut deck = generate_deck();
+        assert_eq!(deck_rank_histogram(&deck), [4; 13]);
+
+        deck.retain(|card| card.rank != Rank::Ace);
+        let histogram = deck_rank_histogram(&deck);
+        assert_eq!(histogram[12], 0);
+        assert_eq!(histogram[..12], [4; 12]);
+    }
+
+    #[test]
+    fn histogram_skips_numbers_without_a_bucket() {
+        let odd = [
+            Card { suit: Suit::Hearts, rank: Rank::Number(1) },
+            Card { suit: Suit::Hearts, rank: Rank::Number(13) },
+            Card { suit: Suit::Hearts, rank: Rank::Number(20) },
+            Card { suit: Suit::Hearts, rank: Rank::Number(10) },
+        ];
+
+        let mut expected = [0; 13];
+        expected[8] = 1;
+        assert_eq!(deck_rank_histogram(&odd), expected);
+    }
+
+    #[test]
+    fn seeded_deals_are_pinned() {
+        // These hands must never change, whatever rand version is in use
+        let hands = GameConfig::new(2, 3).seed(2024).deal().unwrap();
+        assert_eq!(hands, vec![hand("9♠ 10♠ J♦"), hand("8♣ 2♥ 2♦")]);
+
+        assert_eq!(deal_holdem(2, 42).hole_cards, vec![hand("7♥ 3♠"), hand("K♠ Q♣")]);
+    }
+
+    #[test]
+    fn seeded_shuffle_keeps_every_card() {
+        let mut deck = generate_deck();
+        shuffle_seeded(&mut deck, &mut seeded_rng(8));
+
+        assert!(!is_sorted_standard(&deck));
+        assert_eq!(missing_cards(&deck), Vec::new());
+        assert_eq!(validate_unique(&deck), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Player 0 is listed as a winner more than once.")]
+    fn recording_a_seat_twice_panics() {
+        Session::new(3).record(&[0, 2, 0]);
+    }
+
+    #[test]
+    fn numbers_without_a_glyph_give_none() {
+        assert_eq!(display_card_glyph(&Card::new(Rank::Number(1), Suit::Spades)), None);
+        assert_eq!(display_card_glyph(&Card { suit: Suit::Hearts, rank: Rank::Number(0) }), None);
+        assert_eq!(display_card_glyph(&Card { suit: Suit::Hearts, rank: Rank::Number(12) }), None);
+        assert_eq!(display_card_glyph(&Card::new(Rank::Number(2), Suit::Clubs)), Some('🃒'));
+    }
+}
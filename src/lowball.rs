@@ -0,0 +1,100 @@
+use std::cmp::Ordering;
+
+use crate::{rank_groups, rank_value, Card};
+
+// Ordinary high-hand categories, weakest first. In 2-7 lowball a higher
+// category is worse, and straights and flushes count against the hand.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+// A five-card hand under 2-7 (Kansas City) lowball. The Ace is always high, so
+// A-2-3-4-5 is just Ace high. Compares so that the better, lower hand is greater:
+// unsuited 7-5-4-3-2 beats everything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lowball27Rank {
+    pub category: Category,
+    pub values: Vec<u8>, // grouped rank values, biggest group first, Ace as 14
+}
+
+impl Ord for Lowball27Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .category
+            .cmp(&self.category)
+            .then_with(|| other.values.cmp(&self.values))
+    }
+}
+
+impl PartialOrd for Lowball27Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn evaluate_lowball_27(hand: &[Card]) -> Lowball27Rank {
+    let groups = rank_groups(hand);
+    let values: Vec<u8> = groups.iter().map(|(rank, _)| rank_value(rank)).collect();
+    let counts: Vec<usize> = groups.iter().map(|(_, count)| *count).collect();
+
+    let flush = hand.len() == 5 && hand.iter().all(|card| card.suit == hand[0].suit);
+    let straight = hand.len() == 5 && values.len() == 5 && values[0] - values[4] == 4;
+
+    let category = match counts.as_slice() {
+        _ if straight && flush => Category::StraightFlush,
+        [4, ..] => Category::FourOfAKind,
+        [3, 2] => Category::FullHouse,
+        _ if flush => Category::Flush,
+        _ if straight => Category::Straight,
+        [3, ..] => Category::ThreeOfAKind,
+        [2, 2, ..] => Category::TwoPair,
+        [2, ..] => Category::Pair,
+        _ => Category::HighCard,
+    };
+
+    Lowball27Rank { category, values }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Vec<Card> {
+        cards.split_whitespace().map(|card| card.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn seven_five_beats_eight_high() {
+        let seven_five = evaluate_lowball_27(&hand("7S 5H 4D 3C 2S"));
+        let eight_high = evaluate_lowball_27(&hand("8S 5H 4D 3C 2S"));
+
+        assert_eq!(seven_five.category, Category::HighCard);
+        assert!(seven_five > eight_high);
+    }
+
+    #[test]
+    fn wheel_is_only_ace_high() {
+        let wheel = evaluate_lowball_27(&hand("AS 2H 3D 4C 5S"));
+
+        assert_eq!(wheel.category, Category::HighCard);
+        assert_eq!(wheel.values[0], 14);
+        assert!(wheel < evaluate_lowball_27(&hand("KS 5H 4D 3C 2S")));
+    }
+
+    #[test]
+    fn six_cards_with_a_low_pair_are_not_a_straight() {
+        let rank = evaluate_lowball_27(&hand("2S 2H 3D 4C 5S 6H"));
+
+        assert_eq!(rank.category, Category::Pair);
+        assert_eq!(rank.values, vec![2, 6, 5, 4, 3]);
+    }
+}
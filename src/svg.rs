@@ -0,0 +1,70 @@
+use crate::{rank_label, Card, Suit};
+
+const CARD_WIDTH: usize = 60;
+const CARD_HEIGHT: usize = 90;
+const GAP: usize = 10;
+
+// A standalone SVG of the cards in a row, one <g> per card holding its outline,
+// rank and suit. Hearts and diamonds are drawn red, spades and clubs black.
+pub fn hand_to_svg(cards: &[Card]) -> String {
+    let width = if cards.is_empty() {
+        0
+    } else {
+        cards.len() * (CARD_WIDTH + GAP) - GAP
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width, CARD_HEIGHT, width, CARD_HEIGHT
+    );
+
+    for (i, card) in cards.iter().enumerate() {
+        let color = match card.suit {
+            Suit::Hearts | Suit::Diamonds => "#c00",
+            Suit::Spades | Suit::Clubs => "#000",
+        };
+
+        svg.push_str(&format!(
+            "  <g transform=\"translate({}, 0)\">\n",
+            i * (CARD_WIDTH + GAP)
+        ));
+        svg.push_str(&format!(
+            "    <rect width=\"{}\" height=\"{}\" rx=\"6\" fill=\"#fff\" stroke=\"#000\"/>\n",
+            CARD_WIDTH, CARD_HEIGHT
+        ));
+        svg.push_str(&format!(
+            "    <text x=\"6\" y=\"20\" font-size=\"16\" fill=\"{}\">{}</text>\n",
+            color,
+            rank_label(&card.rank)
+        ));
+        svg.push_str(&format!(
+            "    <text x=\"{}\" y=\"{}\" font-size=\"28\" text-anchor=\"middle\" fill=\"{}\">{}</text>\n",
+            CARD_WIDTH / 2,
+            CARD_HEIGHT / 2 + 10,
+            color,
+            card.suit.symbol()
+        ));
+        svg.push_str("  </g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_group_per_card_with_its_rank() {
+        let cards: Vec<Card> = ["A♠", "10♥", "Q♦"].iter().map(|card| card.parse().unwrap()).collect();
+        let svg = hand_to_svg(&cards);
+
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<g ").count(), 3);
+        assert_eq!(svg.matches("<rect ").count(), 3);
+        assert!(svg.contains("fill=\"#000\">A</text>"));
+        assert!(svg.contains("fill=\"#c00\">10</text>"));
+        assert!(svg.contains("fill=\"#c00\">Q</text>"));
+    }
+}
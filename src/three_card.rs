@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+
+use crate::{generate_deck, rank_groups, rank_value, seeded_rng, Card};
+
+// Three Card Poker hand categories, weakest first so the derived ordering ranks
+// hands. A three-card straight is rarer than a flush here, so it sits above it.
+// Ranks are carried as values with the Ace as 14, highest first.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThreeCardRank {
+    HighCard([u8; 3]),
+    Pair(u8, u8), // pair, kicker
+    Flush([u8; 3]),
+    Straight(u8), // top card
+    ThreeOfAKind(u8),
+    StraightFlush(u8), // top card
+}
+
+impl ThreeCardRank {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThreeCardRank::HighCard(_) => "HighCard",
+            ThreeCardRank::Pair(_, _) => "Pair",
+            ThreeCardRank::Flush(_) => "Flush",
+            ThreeCardRank::Straight(_) => "Straight",
+            ThreeCardRank::ThreeOfAKind(_) => "ThreeOfAKind",
+            ThreeCardRank::StraightFlush(_) => "StraightFlush",
+        }
+    }
+}
+
+pub fn evaluate_three(cards: &[Card; 3]) -> ThreeCardRank {
+    let mut values = cards.clone().map(|card| rank_value(&card.rank));
+    values.sort_by(|a, b| b.cmp(a));
+
+    let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+
+    // A-2-3 plays as a straight with the 3 on top
+    let straight_high = if values == [14, 3, 2] {
+        Some(3)
+    } else if values[0] == values[1] + 1 && values[1] == values[2] + 1 {
+        Some(values[0])
+    } else {
+        None
+    };
+
+    let groups = rank_groups(cards);
+
+    match (straight_high, flush) {
+        (Some(high), true) => ThreeCardRank::StraightFlush(high),
+        _ if groups.len() == 1 => ThreeCardRank::ThreeOfAKind(values[0]),
+        (Some(high), false) => ThreeCardRank::Straight(high),
+        (None, true) => ThreeCardRank::Flush(values),
+        _ if groups.len() == 2 => {
+            ThreeCardRank::Pair(rank_value(&groups[0].0), rank_value(&groups[1].0))
+        }
+        _ => ThreeCardRank::HighCard(values),
+    }
+}
+
+// Share of random three-card hands landing in each category, keyed by
+// ThreeCardRank::name, to check against published odds (a straight is about
+// 3.26%). Every category is listed, even if it never came up.
+pub fn three_card_probabilities(iterations: usize, seed: u64) -> HashMap<String, f64> {
+    let mut counts: HashMap<String, usize> = [
+        "HighCard",
+        "Pair",
+        "Flush",
+        "Straight",
+        "ThreeOfAKind",
+        "StraightFlush",
+    ]
+    .into_iter()
+    .map(|name| (name.to_string(), 0))
+    .collect();
+
+    let mut rng = seeded_rng(seed);
+    let mut deck = generate_deck();
+
+    for _ in 0..iterations {
+        let (hand, _) = deck.partial_shuffle(&mut rng, 3);
+        let hand = [hand[0].clone(), hand[1].clone(), hand[2].clone()];
+        *counts.get_mut(evaluate_three(&hand).name()).unwrap() += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|(name, count)| (name, count as f64 / iterations.max(1) as f64))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> [Card; 3] {
+        let cards: Vec<Card> = cards.split_whitespace().map(|card| card.parse().unwrap()).collect();
+        cards.try_into().unwrap()
+    }
+
+    #[test]
+    fn straight_beats_flush() {
+        let straight = evaluate_three(&hand("9S 10H JD"));
+        let flush = evaluate_three(&hand("AH 9H 2H"));
+
+        assert_eq!(straight, ThreeCardRank::Straight(11));
+        assert!(straight > flush);
+    }
+
+    #[test]
+    fn ace_two_three_is_the_lowest_straight() {
+        let wheel = evaluate_three(&hand("AS 2H 3D"));
+
+        assert_eq!(wheel, ThreeCardRank::Straight(3));
+        assert!(wheel > evaluate_three(&hand("KH 8H 4H")));
+        assert!(wheel < evaluate_three(&hand("2S 3H 4D")));
+    }
+
+    #[test]
+    fn pair_is_the_most_common_made_hand() {
+        let odds = three_card_probabilities(20_000, 3);
+
+        assert_eq!(odds.len(), 6);
+        assert!((odds.values().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let most_common = odds
+            .iter()
+            .filter(|(name, _)| name.as_str() != "HighCard")
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap();
+        assert_eq!(most_common.0, "Pair");
+        assert_eq!(three_card_probabilities(1_000, 3), three_card_probabilities(1_000, 3));
+    }
+}